@@ -0,0 +1,105 @@
+//! Companion proc-macro crate for [`plain`](https://crates.io/crates/plain),
+//! providing `#[derive(Plain)]`.
+//!
+//! `plain`'s `Plain` trait is `unsafe`: implementing it on a struct with
+//! implicit padding (or a field that isn't itself `Plain`) is undefined
+//! behavior, but nothing enforces that today short of careful review. This
+//! crate's derive instead verifies it at compile time: it emits a
+//! `unsafe impl Plain for ...` only alongside a `const` assertion that
+//! `size_of::<Struct>()` equals the sum of its fields' sizes (i.e. there is
+//! no compiler-inserted padding), and a `where` bound requiring every field
+//! type to be `Plain`. A struct that doesn't satisfy either simply fails to
+//! compile, rather than producing an unsound impl.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Derives `unsafe impl Plain for ...`, rejecting structs with implicit
+/// padding or non-`Plain` fields at compile time.
+///
+/// Only non-generic `#[repr(C)]` structs with named or tuple fields are
+/// supported; anything else (enums, unions, unit structs, non-`repr(C)`
+/// layouts, generic structs) is rejected with a compile error, since
+/// `plain`'s safety contract requires a defined, padding-free layout.
+#[proc_macro_derive(Plain)]
+pub fn derive_plain(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let is_repr_c = input.attrs.iter().any(|attr| {
+        attr.path().is_ident("repr")
+            && attr
+                .parse_args::<syn::Ident>()
+                .map(|ident| ident == "C")
+                .unwrap_or(false)
+    });
+
+    if !is_repr_c {
+        return syn::Error::new_spanned(&name, "`#[derive(Plain)]` requires `#[repr(C)]`")
+            .to_compile_error()
+            .into();
+    }
+
+    if !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &input.generics,
+            "`#[derive(Plain)]` does not support generic structs",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let fields = match input.data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => fields.named,
+            Fields::Unnamed(fields) => fields.unnamed,
+            Fields::Unit => {
+                return syn::Error::new_spanned(
+                    &name,
+                    "`#[derive(Plain)]` does not support unit structs",
+                )
+                .to_compile_error()
+                .into();
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(
+                &name,
+                "`#[derive(Plain)]` only supports structs",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+
+    let size_sum = if field_types.is_empty() {
+        quote! { 0 }
+    } else {
+        quote! { #( ::core::mem::size_of::<#field_types>() )+* }
+    };
+
+    let assert_name = syn::Ident::new(
+        &format!("__PLAIN_NO_PADDING_{}", name),
+        proc_macro2::Span::call_site(),
+    );
+
+    let expanded = quote! {
+        #[allow(non_upper_case_globals)]
+        const #assert_name: () = assert!(
+            ::core::mem::size_of::<#name>() == #size_sum,
+            "derive(Plain) found implicit padding in this struct"
+        );
+
+        unsafe impl ::plain::Plain for #name
+        where
+            #( #field_types: ::plain::Plain, )*
+        {}
+    };
+
+    expanded.into()
+}
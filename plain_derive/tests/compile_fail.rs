@@ -0,0 +1,5 @@
+#[test]
+fn rejects_bad_derive_plain_inputs() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
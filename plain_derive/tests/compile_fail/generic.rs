@@ -0,0 +1,9 @@
+use plain::Plain;
+
+#[repr(C)]
+#[derive(Plain)]
+struct Generic<T> {
+    value: T,
+}
+
+fn main() {}
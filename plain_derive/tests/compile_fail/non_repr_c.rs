@@ -0,0 +1,9 @@
+use plain::Plain;
+
+#[derive(Plain)]
+struct NotReprC {
+    a: u32,
+    b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use plain::Plain;
+
+#[repr(C)]
+#[derive(Plain)]
+struct Padded {
+    a: u8,
+    b: u32,
+}
+
+fn main() {}
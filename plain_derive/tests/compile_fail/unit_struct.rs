@@ -0,0 +1,7 @@
+use plain::Plain;
+
+#[repr(C)]
+#[derive(Plain)]
+struct UnitStruct;
+
+fn main() {}
@@ -0,0 +1,17 @@
+use plain::Plain;
+
+#[repr(C)]
+#[derive(Plain, Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[test]
+fn derives_plain_and_round_trips_through_from_bytes() {
+    let original = Point { x: 0x11223344, y: 0x55667788 };
+    let bytes = original.as_bytes();
+
+    let restored: &Point = plain::from_bytes(bytes).unwrap();
+    assert_eq!(*restored, original);
+}
@@ -0,0 +1,183 @@
+//! Endian-aware integer wrappers that are [`Plain`](../trait.Plain.html) at
+//! any alignment.
+//!
+//! Reinterpreting a byte slice as a native integer type via [`from_bytes()`]
+//! yields host-endian values and requires the type's native alignment,
+//! which makes parsing fixed-endian wire/file formats awkward: a `u32`
+//! field in a big-endian on-disk struct needs a byte-swap on little-endian
+//! hosts, and every multi-byte field needs `size_of`-aligned placement.
+//!
+//! The wrapper types in this module (`U16`, `U32`, `U64`, `U128`, `I16`,
+//! `I32`, `I64`, `I128`) sidestep both problems: each one stores its value
+//! as a
+//! `#[repr(C)]` byte array rather than a native integer, so its alignment
+//! is always 1 and it unconditionally implements
+//! [`Plain`](../trait.Plain.html). The order marker type parameter (one of
+//! [`BigEndian`](struct.BigEndian.html), [`LittleEndian`](struct.LittleEndian.html),
+//! or [`NativeEndian`](struct.NativeEndian.html)) determines how
+//! [`get()`](struct.U16.html#method.get) and [`set()`](struct.U16.html#method.set)
+//! interpret the stored bytes.
+//!
+//! [`from_bytes()`]: ../fn.from_bytes.html
+//!
+//! # Example
+//!
+//! ```
+//! use plain::Plain;
+//! use plain::byteorder::{BigEndian, U32};
+//!
+//! #[repr(C)]
+//! struct Header {
+//!     magic: U32<BigEndian>,
+//! }
+//!
+//! unsafe impl Plain for Header {}
+//!
+//! let bytes = [0x00, 0x00, 0x00, 0x2A];
+//! let header: &Header = plain::from_bytes(&bytes).unwrap();
+//! assert_eq!(header.magic.get(), 42);
+//! ```
+
+use core::marker::PhantomData;
+
+use super::{Plain, Unaligned};
+
+/// A marker for big-endian byte order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BigEndian {}
+
+/// A marker for little-endian byte order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LittleEndian {}
+
+/// A marker for the target platform's native byte order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NativeEndian {}
+
+/// A trait implemented by the byte-order marker types
+/// ([`BigEndian`](enum.BigEndian.html), [`LittleEndian`](enum.LittleEndian.html),
+/// [`NativeEndian`](enum.NativeEndian.html)) used to parameterize the
+/// wrapper types in this module.
+pub trait ByteOrder {
+    #[doc(hidden)]
+    fn is_big_endian() -> bool;
+}
+
+impl ByteOrder for BigEndian {
+    #[inline(always)]
+    fn is_big_endian() -> bool {
+        true
+    }
+}
+
+impl ByteOrder for LittleEndian {
+    #[inline(always)]
+    fn is_big_endian() -> bool {
+        false
+    }
+}
+
+impl ByteOrder for NativeEndian {
+    #[inline(always)]
+    fn is_big_endian() -> bool {
+        cfg!(target_endian = "big")
+    }
+}
+
+macro_rules! define_endian_type {
+    ($name:ident, $native:ty, $size:expr) => {
+        /// An endian-aware wrapper around a
+        #[doc = concat!("`", stringify!($native), "`")]
+        /// stored as a byte array, so it is always `Plain` and has
+        /// alignment 1.
+        #[repr(C)]
+        pub struct $name<O> {
+            bytes: [u8; $size],
+            _order: PhantomData<O>,
+        }
+
+        impl<O: ByteOrder> $name<O> {
+            /// Wraps a native-endian value, storing it according to `O`.
+            #[inline]
+            pub fn new(value: $native) -> Self {
+                let mut w = $name { bytes: [0; $size], _order: PhantomData };
+                w.set(value);
+                w
+            }
+
+            /// Returns the wrapped value, converted to host byte order.
+            #[inline]
+            pub fn get(&self) -> $native {
+                if O::is_big_endian() {
+                    <$native>::from_be_bytes(self.bytes)
+                } else {
+                    <$native>::from_le_bytes(self.bytes)
+                }
+            }
+
+            /// Overwrites the wrapped value, storing `value` according to `O`.
+            #[inline]
+            pub fn set(&mut self, value: $native) {
+                self.bytes = if O::is_big_endian() {
+                    value.to_be_bytes()
+                } else {
+                    value.to_le_bytes()
+                };
+            }
+        }
+
+        impl<O: ByteOrder> Clone for $name<O> {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<O: ByteOrder> Copy for $name<O> {}
+
+        impl<O: ByteOrder> core::fmt::Debug for $name<O> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+            }
+        }
+
+        impl<O: ByteOrder> PartialEq for $name<O> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.bytes == other.bytes
+            }
+        }
+
+        impl<O: ByteOrder> Eq for $name<O> {}
+
+        impl<O: ByteOrder> From<$native> for $name<O> {
+            #[inline]
+            fn from(value: $native) -> Self {
+                $name::new(value)
+            }
+        }
+
+        impl<O: ByteOrder> From<$name<O>> for $native {
+            #[inline]
+            fn from(w: $name<O>) -> $native {
+                w.get()
+            }
+        }
+
+        // SAFE: the only field is a byte array, which has no invalid
+        // bit patterns and alignment 1.
+        unsafe impl<O> Plain for $name<O> {}
+
+        // SAFE: the only field is a `[u8; N]`, whose alignment is 1.
+        unsafe impl<O> Unaligned for $name<O> {}
+    }
+}
+
+define_endian_type!(U16, u16, 2);
+define_endian_type!(U32, u32, 4);
+define_endian_type!(U64, u64, 8);
+define_endian_type!(I16, i16, 2);
+define_endian_type!(I32, i32, 4);
+define_endian_type!(I64, i64, 8);
+define_endian_type!(U128, u128, 16);
+define_endian_type!(I128, i128, 16);
@@ -98,15 +98,39 @@
 //! it provides tools to handle endianness properly.
 //!
 //! In short, `plain` is much, much _plainer_...
+//!
+//! # Deriving `Plain`
+//!
+//! Hand-writing `unsafe impl Plain for MyStruct {}` silently invokes
+//! undefined behavior if `MyStruct` turns out to have implicit padding or
+//! a field that isn't itself `Plain`. Enabling the `derive` feature pulls
+//! in the companion `plain_derive` crate and re-exports its
+//! `#[derive(Plain)]` macro here, which checks both of those things at
+//! compile time (via a `const` assertion on `size_of` and a `where` bound
+//! on every field) before emitting the `unsafe impl`, so a struct that
+//! violates the contract fails to compile instead of compiling unsoundly.
+//!
+//! ```toml
+//! [dependencies]
+//! plain = { version = "0.2", features = ["derive"] }
+//! ```
 
 #![no_std]
 
+#[cfg(feature = "derive")]
+extern crate plain_derive;
+
+#[cfg(feature = "derive")]
+pub use plain_derive::Plain;
+
 #[cfg(test)]
 #[macro_use]
 extern crate std;
 
 use core::{mem, slice};
 
+pub mod byteorder;
+
 /// A trait for plain reinterpretable data.
 ///
 /// A type can be [`Plain`](trait.Plain.html) if it is `#repr(C)` and only contains
@@ -178,6 +202,26 @@ pub unsafe trait Plain {
     fn as_mut_bytes(&mut self) -> &mut [u8] {
         self::as_mut_bytes(self)
     }
+
+    /// Returns an all-zero instance of `Self`.
+    ///
+    /// Since every [`Plain`](trait.Plain.html) type by contract has no
+    /// invalid bit patterns, an all-zero buffer is always a valid `Self`,
+    /// so this is sound for any `Plain` type without also requiring
+    /// `Default`.
+    #[inline(always)]
+    fn zeroed() -> Self
+        where Self: Sized
+    {
+        unsafe { mem::zeroed() }
+    }
+
+    /// Overwrites `self` with all-zero bytes.
+    #[inline(always)]
+    fn zero(&mut self) {
+        let bytes = self.as_mut_bytes();
+        unsafe { core::ptr::write_bytes(bytes.as_mut_ptr(), 0, bytes.len()) }
+    }
 }
 
 unsafe impl Plain for u8 {}
@@ -197,11 +241,113 @@ unsafe impl Plain for f64 {}
 
 unsafe impl<S> Plain for [S] where S: Plain {}
 
+/// A trait for data for which not every bit pattern is valid, but whose
+/// validity can be checked against a candidate byte slice.
+///
+/// Unlike [`Plain`](trait.Plain.html), a `TryPlain` type may reject some
+/// inputs: `bool` is only valid for the bytes `0` and `1`, `char` is only
+/// valid for byte patterns that form a valid Unicode scalar value, and
+/// `#[repr(C)]` structs containing such fields are only valid if every
+/// field is. [`try_from_bytes()`](fn.try_from_bytes.html) performs the
+/// usual size/alignment checks and then consults
+/// [`is_bit_valid()`](#tymethod.is_bit_valid) before handing back a
+/// reference, so a rejected buffer never gets reinterpreted.
+///
+/// Every [`Plain`](trait.Plain.html) type is trivially `TryPlain`, since by
+/// definition all of its bit patterns are valid.
+///
+pub unsafe trait TryPlain {
+    /// Returns whether `bytes` (which is guaranteed by the caller to be at
+    /// least `size_of::<Self>()` long) holds a valid bit pattern for `Self`.
+    fn is_bit_valid(bytes: &[u8]) -> bool
+        where Self: Sized;
+}
+
+unsafe impl<T> TryPlain for T
+    where T: Plain
+{
+    #[inline(always)]
+    fn is_bit_valid(_bytes: &[u8]) -> bool {
+        // A `Plain` type has no invalid bit patterns by contract.
+        true
+    }
+}
+
+unsafe impl TryPlain for bool {
+    #[inline]
+    fn is_bit_valid(bytes: &[u8]) -> bool {
+        bytes[0] == 0 || bytes[0] == 1
+    }
+}
+
+unsafe impl TryPlain for char {
+    #[inline]
+    fn is_bit_valid(bytes: &[u8]) -> bool {
+        let v = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        v < 0xD800 || (0xE000..=0x10FFFF).contains(&v)
+    }
+}
+
+macro_rules! impl_try_plain_nonzero {
+    ($($nonzero:ident: $native:ty;)+) => {
+        $(
+            unsafe impl TryPlain for core::num::$nonzero {
+                #[inline]
+                fn is_bit_valid(bytes: &[u8]) -> bool {
+                    let mut buf = [0u8; mem::size_of::<$native>()];
+                    buf.copy_from_slice(&bytes[..mem::size_of::<$native>()]);
+                    <$native>::from_ne_bytes(buf) != 0
+                }
+            }
+        )+
+    }
+}
+
+impl_try_plain_nonzero! {
+    NonZeroU8: u8;
+    NonZeroU16: u16;
+    NonZeroU32: u32;
+    NonZeroU64: u64;
+    NonZeroI8: i8;
+    NonZeroI16: i16;
+    NonZeroI32: i32;
+    NonZeroI64: i64;
+}
+
+/// Validates the bytes of a single field of a `#[repr(C)]` struct at a
+/// known `offset`, for use inside a hand-written
+/// [`TryPlain::is_bit_valid()`](trait.TryPlain.html#tymethod.is_bit_valid)
+/// impl.
+///
+/// `bytes` must be at least `offset + size_of::<F>()` long; this is the
+/// caller's responsibility, same as for `is_bit_valid` itself.
+#[inline]
+pub fn is_bit_valid_field<F: TryPlain>(bytes: &[u8], offset: usize) -> bool {
+    F::is_bit_valid(&bytes[offset..offset + mem::size_of::<F>()])
+}
+
+/// A marker for [`Plain`](trait.Plain.html) types whose alignment is 1.
+///
+/// Since `check_alignment` requires `address % align_of::<T>() == 0`, any
+/// type with `align_of::<T>() == 1` trivially satisfies it at every
+/// address. [`from_bytes()`](fn.from_bytes.html) and
+/// [`slice_from_bytes()`](fn.slice_from_bytes.html) can therefore never
+/// return [`Error::BadAlignment`](enum.Error.html) for an `Unaligned` type,
+/// no matter where in a buffer it sits — useful for documenting that a
+/// type is safe to place at arbitrary offsets in packed network/file
+/// formats, such as the [`byteorder`](byteorder/index.html) wrapper types.
+pub unsafe trait Unaligned: Plain {}
+
+unsafe impl Unaligned for u8 {}
+unsafe impl Unaligned for i8 {}
+
+unsafe impl<S> Unaligned for [S] where S: Unaligned {}
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Error {
     TooShort,
     BadAlignment,
+    Invalid,
 }
 
 #[inline(always)]
@@ -294,6 +440,53 @@ pub fn from_bytes<T>(bytes: &[u8]) -> Result<&T, Error>
     Ok(unsafe { &*(bytes.as_ptr() as *const T) })
 }
 
+/// Reads an owned `T` out of a byte slice that isn't necessarily aligned
+/// for `T`.
+///
+/// Where [`from_bytes()`](fn.from_bytes.html) hands back a zero-copy
+/// reference and therefore has to reject slices that aren't aligned for
+/// `T`, `read_from_bytes()` only checks the length and then does an
+/// unaligned read (`ptr::read_unaligned`) to produce `T` by value. This
+/// never returns [`Error::BadAlignment`](enum.Error.html), at the cost of
+/// a copy, making it the right choice for arbitrarily-aligned input like
+/// network packets or a buffer `mmap`'d at an arbitrary offset.
+///
+#[inline]
+pub fn read_from_bytes<T>(bytes: &[u8]) -> Result<T, Error>
+    where T: Plain
+{
+    try!(check_instance_size::<T>(bytes));
+    Ok(unsafe { (bytes.as_ptr() as *const T).read_unaligned() })
+}
+
+/// Alias for [`read_from_bytes()`](fn.read_from_bytes.html).
+#[inline(always)]
+pub fn copy_from_bytes<T>(bytes: &[u8]) -> Result<T, Error>
+    where T: Plain
+{
+    read_from_bytes(bytes)
+}
+
+/// Fills `out` by copying `out.len()` consecutive `T`s out of `bytes`,
+/// which isn't necessarily aligned for `T`.
+///
+/// This is the slice counterpart of
+/// [`copy_from_bytes()`](fn.copy_from_bytes.html): each element is copied
+/// out with an unaligned read, so `bytes` only needs to be long enough,
+/// never aligned for `T`.
+///
+#[inline]
+pub fn copy_slice_from_bytes<T>(bytes: &[u8], out: &mut [T]) -> Result<(), Error>
+    where T: Plain
+{
+    try!(check_slice_size::<T>(bytes, out.len()));
+    for (i, slot) in out.iter_mut().enumerate() {
+        let start = i * mem::size_of::<T>();
+        *slot = unsafe { (bytes[start..].as_ptr() as *const T).read_unaligned() };
+    }
+    Ok(())
+}
+
 /// Similar to [`from_bytes()`](fn.from_bytes.html),
 /// except that the output is a slice of T, instead
 /// of a reference to a single T. All concerns about
@@ -366,6 +559,38 @@ pub fn from_mut_bytes<T>(bytes: &mut [u8]) -> Result<&mut T, Error>
     Ok(unsafe { &mut *(bytes.as_mut_ptr() as *mut T) })
 }
 
+/// Returns an all-zero `T`.
+///
+/// Free-function form of [`Plain::zeroed()`](trait.Plain.html#method.zeroed),
+/// for call sites that would rather not name the trait.
+#[inline(always)]
+pub fn zeroed<T: Plain>() -> T {
+    T::zeroed()
+}
+
+/// Overwrites every element of `slice` with all-zero bytes.
+#[inline]
+pub fn zero_slice<T: Plain>(slice: &mut [T]) {
+    for item in slice.iter_mut() {
+        item.zero();
+    }
+}
+
+/// Zero-fills `bytes` and reinterprets it as `&mut T`, for callers that
+/// want to build up a fresh `T` in place field-by-field before calling
+/// [`as_bytes()`](fn.as_bytes.html) on it.
+///
+/// This is [`from_mut_bytes()`](fn.from_mut_bytes.html) plus the zeroing
+/// that building a packet/struct to send usually starts with.
+#[inline]
+pub fn from_mut_bytes_zeroed<T: Plain>(bytes: &mut [u8]) -> Result<&mut T, Error> {
+    try!(check_instance_size::<T>(bytes));
+    try!(check_alignment::<T>(bytes));
+    let size = mem::size_of::<T>();
+    unsafe { core::ptr::write_bytes(bytes.as_mut_ptr(), 0, size) };
+    from_mut_bytes(bytes)
+}
+
 /// See [`slice_from_bytes()`](fn.slice_from_bytes.html).
 ///
 /// Does the same, except with mutable references.
@@ -389,5 +614,145 @@ pub fn slice_from_mut_bytes_len<T>(bytes: &mut [u8], len: usize) -> Result<&mut
     Ok(unsafe { slice::from_raw_parts_mut(bytes.as_ptr() as *mut T, len) })
 }
 
+/// Splits off a `T`-sized prefix of `bytes`, reinterpreting it as `&T`,
+/// and returns it along with the untouched remainder of the slice.
+///
+/// This is like [`from_bytes()`](fn.from_bytes.html), except that instead
+/// of requiring (and discarding) any extra bytes, it hands the tail back
+/// to the caller. This is the common "parse a header, then parse whatever
+/// follows it" pattern, e.g. an ELF header followed by its program
+/// headers, without manual offset arithmetic.
+///
+#[inline]
+pub fn from_prefix<T>(bytes: &[u8]) -> Result<(&T, &[u8]), Error>
+    where T: Plain
+{
+    let header = try!(from_bytes::<T>(bytes));
+    let (_, rest) = bytes.split_at(mem::size_of::<T>());
+    Ok((header, rest))
+}
+
+/// See [`from_prefix()`](fn.from_prefix.html).
+///
+/// Does the same, except with mutable references.
+#[inline]
+pub fn from_mut_prefix<T>(bytes: &mut [u8]) -> Result<(&mut T, &mut [u8]), Error>
+    where T: Plain
+{
+    try!(check_instance_size::<T>(bytes));
+    try!(check_alignment::<T>(bytes));
+    let (head, rest) = bytes.split_at_mut(mem::size_of::<T>());
+    Ok((unsafe { &mut *(head.as_mut_ptr() as *mut T) }, rest))
+}
+
+/// Splits off `count` elements worth of `T` from the front of `bytes`,
+/// reinterpreting them as `&[T]`, and returns them along with the
+/// untouched remainder of the slice.
+///
+/// This is the slice counterpart of [`from_prefix()`](fn.from_prefix.html):
+/// where `from_prefix()` peels off a single header, `slice_from_prefix()`
+/// peels off a fixed-size run of repeated elements, e.g. an ELF header's
+/// array of program headers.
+///
+#[inline]
+pub fn slice_from_prefix<T>(bytes: &[u8], count: usize) -> Result<(&[T], &[u8]), Error>
+    where T: Plain
+{
+    let items = try!(slice_from_bytes_len::<T>(bytes, count));
+    let (_, rest) = bytes.split_at(count * mem::size_of::<T>());
+    Ok((items, rest))
+}
+
+/// See [`slice_from_prefix()`](fn.slice_from_prefix.html).
+///
+/// Does the same, except with mutable references.
+#[inline]
+pub fn slice_from_mut_prefix<T>(bytes: &mut [u8], count: usize) -> Result<(&mut [T], &mut [u8]), Error>
+    where T: Plain
+{
+    try!(check_alignment::<T>(bytes));
+    try!(check_slice_size::<T>(bytes, count));
+    let (head, rest) = bytes.split_at_mut(count * mem::size_of::<T>());
+    Ok((unsafe { slice::from_raw_parts_mut(head.as_mut_ptr() as *mut T, count) }, rest))
+}
+
+/// Splits `bytes` into a `&H` header followed by a `&[T]` tail made up of
+/// however many whole `T`s fit in what remains.
+///
+/// This is the "fixed header, then N repeating records" pattern: it's
+/// equivalent to [`from_prefix::<H>(bytes)`](fn.from_prefix.html) followed
+/// by [`slice_from_bytes::<T>()`](fn.slice_from_bytes.html) on the
+/// leftover bytes, bundled into one call. The tail length is simply
+/// `remaining.len() / size_of::<T>()`; any trailing bytes that don't
+/// complete another `T` are silently left out, same as
+/// `slice_from_bytes()`.
+///
+#[inline]
+pub fn from_bytes_with_tail<H, T>(bytes: &[u8]) -> Result<(&H, &[T]), Error>
+    where H: Plain, T: Plain
+{
+    let (header, rest) = try!(from_prefix::<H>(bytes));
+    let tail = try!(slice_from_bytes::<T>(rest));
+    Ok((header, tail))
+}
+
+/// See [`from_bytes_with_tail()`](fn.from_bytes_with_tail.html).
+///
+/// Does the same, except with mutable references.
+#[inline]
+pub fn from_mut_bytes_with_tail<H, T>(bytes: &mut [u8]) -> Result<(&mut H, &mut [T]), Error>
+    where H: Plain, T: Plain
+{
+    let (header, rest) = try!(from_mut_prefix::<H>(bytes));
+    let tail = try!(slice_from_mut_bytes::<T>(rest));
+    Ok((header, tail))
+}
+
+/// Safely converts a byte slice to a reference, validating that the bytes
+/// actually hold a legal `T` first.
+///
+/// This is the `T: TryPlain` counterpart of
+/// [`from_bytes()`](fn.from_bytes.html): it performs the same size and
+/// alignment checks, but additionally calls
+/// [`T::is_bit_valid()`](trait.TryPlain.html#tymethod.is_bit_valid) on the
+/// candidate bytes and returns [`Error::Invalid`](enum.Error.html) if they
+/// don't form a valid `T`. This makes it sound to reinterpret types like
+/// `bool`, `char`, or structs containing them, which `from_bytes()` cannot
+/// handle.
+///
+#[inline]
+pub fn try_from_bytes<T>(bytes: &[u8]) -> Result<&T, Error>
+    where T: TryPlain
+{
+    try!(check_instance_size::<T>(bytes));
+    try!(check_alignment::<T>(bytes));
+    if !T::is_bit_valid(bytes) {
+        return Err(Error::Invalid);
+    }
+    Ok(unsafe { &*(bytes.as_ptr() as *const T) })
+}
+
+/// Similar to [`try_from_bytes()`](fn.try_from_bytes.html), except that the
+/// output is a slice of `T`, instead of a reference to a single `T`.
+///
+/// Every element of the resulting slice is validated individually; if any
+/// one of them fails [`is_bit_valid()`](trait.TryPlain.html#tymethod.is_bit_valid),
+/// the whole call fails with [`Error::Invalid`](enum.Error.html).
+///
+#[inline]
+pub fn try_slice_from_bytes<T>(bytes: &[u8]) -> Result<&[T], Error>
+    where T: TryPlain
+{
+    try!(check_alignment::<T>(bytes));
+    let len = bytes.len() / mem::size_of::<T>();
+    let size = mem::size_of::<T>();
+    for i in 0..len {
+        if !T::is_bit_valid(&bytes[i * size..(i + 1) * size]) {
+            return Err(Error::Invalid);
+        }
+    }
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T, len) })
+}
+
 #[cfg(test)]
 mod tests;
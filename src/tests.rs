@@ -1,5 +1,6 @@
 
 use super::*;
+use super::byteorder::{BigEndian, LittleEndian, NativeEndian, I16, I32, I64, I128, U16, U32, U64, U128};
 use core::mem;
 
 #[repr(C)]
@@ -85,3 +86,201 @@ fn basic_function() {
     assert!(r6.len() == 1);
     assert!(t1 == r6[0]);
 }
+
+#[test]
+fn zero_slice_and_free_zeroed() {
+    let z: Dummy1 = zeroed();
+    assert!(z == Dummy1::zeroed());
+
+    let mut arr = [
+        Dummy1 { field1: 1, field2: 1, field3: 1, field4: 1, field5: 1 },
+        Dummy1 { field1: 2, field2: 2, field3: 2, field4: 2, field5: 2 },
+    ];
+    zero_slice(&mut arr);
+    assert!(arr[0] == z && arr[1] == z);
+}
+
+#[test]
+fn from_mut_bytes_zeroed_fills_target() {
+    let mut b = vec![0xffu8; mem::size_of::<Dummy1>()];
+
+    let h: &mut Dummy1 = from_mut_bytes_zeroed(&mut b).unwrap();
+    assert!(*h == Dummy1::zeroed());
+}
+
+#[test]
+fn from_bytes_with_tail_splits_header_and_records() {
+    let b = vec![0u8; mem::size_of::<Dummy1>() + mem::size_of::<Dummy2>() * 2];
+
+    let (header, tail): (&Dummy1, &[Dummy2]) = from_bytes_with_tail(&b).unwrap();
+    assert!(header == &Dummy1 { field1: 0, field2: 0, field3: 0, field4: 0, field5: 0 });
+    assert!(tail.len() == 2);
+}
+
+#[test]
+fn try_from_bytes_nonzero() {
+    let zero = 0u32.to_ne_bytes();
+    let nonzero = 5u32.to_ne_bytes();
+
+    assert!(try_from_bytes::<core::num::NonZeroU32>(&zero) == Err(Error::Invalid));
+    assert!(try_from_bytes::<core::num::NonZeroU32>(&nonzero).unwrap().get() == 5);
+}
+
+#[test]
+fn copy_slice_from_bytes_unaligned() {
+    let b = vec![0u8; mem::size_of::<Dummy1>() * 2 + 1];
+    let b = &b[1..];
+
+    let mut out = [Dummy1 { field1: 1, field2: 1, field3: 1, field4: 1, field5: 1 }; 2];
+    copy_slice_from_bytes(&b, &mut out).unwrap();
+    assert!(out[0] == Dummy1 { field1: 0, field2: 0, field3: 0, field4: 0, field5: 0 });
+    assert!(out[1] == out[0]);
+}
+
+#[test]
+fn read_from_bytes_unaligned() {
+    let b = vec![0u8; mem::size_of::<Dummy1>() + 1];
+    let b = &b[1..];
+
+    // Would fail with BadAlignment via from_bytes(), but not here.
+    let r: Dummy1 = read_from_bytes(&b).unwrap();
+    assert!(r == Dummy1 { field1: 0, field2: 0, field3: 0, field4: 0, field5: 0 });
+}
+
+#[test]
+fn zeroed_and_zero() {
+    let z = Dummy1::zeroed();
+    assert!(z.field1 == 0 && z.field2 == 0 && z.field3 == 0 && z.field4 == 0 && z.field5 == 0);
+
+    let mut t = Dummy1 {
+        field1: 1,
+        field2: 2,
+        field3: 3,
+        field4: 4,
+        field5: 5,
+    };
+    t.zero();
+    assert!(t == z);
+}
+
+#[test]
+fn from_prefix_splits_tail() {
+    let b = vec![0u8; mem::size_of::<Dummy1>() + 3];
+
+    let (header, tail): (&Dummy1, &[u8]) = from_prefix(&b).unwrap();
+    assert!(header == &Dummy1 { field1: 0, field2: 0, field3: 0, field4: 0, field5: 0 });
+    assert!(tail.len() == 3);
+}
+
+#[test]
+fn try_from_bytes_bool() {
+    let good = [0u8];
+    let bad = [2u8];
+
+    assert!(try_from_bytes::<bool>(&good) == Ok(&false));
+    assert!(try_from_bytes::<bool>(&bad) == Err(Error::Invalid));
+}
+
+#[test]
+fn try_from_bytes_char() {
+    let good = ('A' as u32).to_ne_bytes();
+    let bad = 0xD800u32.to_ne_bytes();
+
+    assert!(try_from_bytes::<char>(&good) == Ok(&'A'));
+    assert!(try_from_bytes::<char>(&bad) == Err(Error::Invalid));
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Flagged {
+    flag: bool,
+    _pad: [u8; 3],
+    value: u32,
+}
+
+unsafe impl TryPlain for Flagged {
+    fn is_bit_valid(bytes: &[u8]) -> bool {
+        is_bit_valid_field::<bool>(bytes, 0) && is_bit_valid_field::<u32>(bytes, 4)
+    }
+}
+
+#[test]
+fn try_from_bytes_struct_field_validation() {
+    let mut good = vec![1u8, 0, 0, 0, 5, 0, 0, 0];
+    let bad = vec![2u8, 0, 0, 0, 5, 0, 0, 0];
+
+    let r: &Flagged = try_from_bytes(&good).unwrap();
+    assert!(r.flag && r.value == 5);
+
+    assert!(try_from_bytes::<Flagged>(&bad) == Err(Error::Invalid));
+
+    good[0] = 0;
+    let r2: &Flagged = try_from_bytes(&good).unwrap();
+    assert!(!r2.flag);
+}
+
+#[test]
+fn byteorder_u16_byte_layout() {
+    assert!(U16::<BigEndian>::new(0x1234).as_bytes() == &[0x12, 0x34]);
+    assert!(U16::<LittleEndian>::new(0x1234).as_bytes() == &[0x34, 0x12]);
+}
+
+#[test]
+fn byteorder_get_matches_layout() {
+    let be = U32::<BigEndian>::from_bytes(&[0x00, 0x00, 0x00, 0x2A]).unwrap();
+    let le = U32::<LittleEndian>::from_bytes(&[0x2A, 0x00, 0x00, 0x00]).unwrap();
+
+    assert!(be.get() == 42);
+    assert!(le.get() == 42);
+}
+
+#[test]
+fn byteorder_native_matches_target_endian() {
+    let n = U16::<NativeEndian>::new(0x1234);
+
+    if cfg!(target_endian = "big") {
+        assert!(n.as_bytes() == &[0x12, 0x34]);
+    } else {
+        assert!(n.as_bytes() == &[0x34, 0x12]);
+    }
+}
+
+#[test]
+fn byteorder_set_overwrites_value() {
+    let mut w = U64::<BigEndian>::new(0);
+    w.set(0x0102030405060708);
+    assert!(w.as_bytes() == &[1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn byteorder_signed_round_trip() {
+    let w = I32::<LittleEndian>::new(-1);
+    assert!(w.as_bytes() == &[0xFF, 0xFF, 0xFF, 0xFF]);
+    assert!(w.get() == -1);
+
+    let w16 = I16::<BigEndian>::new(-2);
+    assert!(w16.get() == -2);
+
+    let w64 = I64::<LittleEndian>::new(-3);
+    assert!(w64.get() == -3);
+}
+
+#[test]
+fn byteorder_128_bit_round_trip() {
+    let u = U128::<BigEndian>::new(0x1122_3344_5566_7788_99AA_BBCC_DDEE_FF00);
+    assert!(u.as_bytes()[0] == 0x11 && u.as_bytes()[15] == 0x00);
+    assert!(u.get() == 0x1122_3344_5566_7788_99AA_BBCC_DDEE_FF00);
+
+    let i = I128::<LittleEndian>::new(-1);
+    assert!(i.as_bytes() == &[0xFF; 16]);
+}
+
+#[test]
+fn byteorder_from_into_round_trip() {
+    let w: U16<BigEndian> = 0x1234.into();
+    let back: u16 = w.into();
+    assert!(back == 0x1234);
+
+    let w2 = U32::<LittleEndian>::from(7u32);
+    assert!(u32::from(w2) == 7);
+}